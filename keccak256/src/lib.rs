@@ -25,4 +25,18 @@ lazy_static::lazy_static! {
 
         EMPTY_HASH.iter().rev().cloned().collect_vec().try_into().unwrap()
     };
+    /// Root of an empty Merkle Patricia Trie, `keccak(rlp(""))`.
+    pub static ref EMPTY_TRIE_ROOT: [u8; 32] = {
+        use std::convert::TryInto;
+
+        let mut keccak = plain::Keccak::default();
+        keccak.update(&[0x80]);
+        keccak.digest().try_into().unwrap()
+    };
+    pub static ref EMPTY_TRIE_ROOT_LE: [u8; 32] = {
+        use std::convert::TryInto;
+        use itertools::Itertools;
+
+        EMPTY_TRIE_ROOT.iter().rev().cloned().collect_vec().try_into().unwrap()
+    };
 }
@@ -197,6 +197,16 @@ fn test_empty_input() {
     assert_eq!(keccak256(&[]), output);
 }
 
+#[test]
+fn test_empty_trie_root() {
+    let output = [
+        86, 232, 31, 23, 27, 204, 85, 166, 255, 131, 69, 230, 146, 192, 248, 110, 91, 72, 224, 27,
+        153, 108, 173, 192, 1, 98, 47, 181, 227, 99, 180, 33,
+    ];
+    assert_eq!(keccak256(&[0x80]), output);
+    assert_eq!(*crate::EMPTY_TRIE_ROOT, output);
+}
+
 #[test]
 fn test_short_input() {
     let output = [